@@ -0,0 +1,109 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Builds an `AdbcConnectionManager` straight from an ADBC driver library,
+//! without requiring the caller to load the driver or build a `Database`
+//! themselves.
+
+use std::sync::OnceLock;
+
+use adbc_core::options::{AdbcVersion, OptionDatabase, OptionValue};
+use adbc_core::{Database, Driver};
+use adbc_driver_manager::{ManagedDatabase, ManagedDriver};
+
+use crate::{AdbcConnectionManager, AdbcError, DatabaseSource};
+
+/// Lets [`AdbcConnectionManager::from_driver`] target a `Database` type other
+/// than [`ManagedDatabase`] itself.
+///
+/// The `rocket` feature needs this: Rust's orphan rules forbid implementing
+/// `rocket_sync_db_pools::Poolable` (a foreign trait) for `ManagedConnection`
+/// (a foreign type) directly, so it wraps `ManagedDatabase`/`ManagedConnection`
+/// in local newtypes. Implementing this trait for such a newtype lets it reuse
+/// `from_driver`'s driver-loading logic instead of duplicating it.
+pub trait FromManagedDatabase: Database + Sized {
+    fn from_managed(database: ManagedDatabase) -> Self;
+}
+
+impl FromManagedDatabase for ManagedDatabase {
+    fn from_managed(database: ManagedDatabase) -> Self {
+        database
+    }
+}
+
+impl<D> AdbcConnectionManager<D>
+where
+    D: FromManagedDatabase,
+{
+    /// Creates an `AdbcConnectionManager` that loads `driver` and builds its
+    /// `Database` lazily, the first time a connection is requested.
+    ///
+    /// This lets the manager own the whole driver/database lifecycle instead
+    /// of requiring the caller to keep a `Driver` and `Database` alive, the
+    /// way `r2d2_odbc` builds a manager from a connection string and diesel
+    /// builds one from a URL. Any failure loading the driver or building the
+    /// database is surfaced as an [`AdbcError`] from the first call to
+    /// `connect`, not from this constructor.
+    ///
+    /// # Arguments
+    ///
+    /// * `driver` - The path or name of the ADBC driver shared library to load
+    /// * `options` - Database-level options passed when the database is built
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use adbc_driver_manager::ManagedDatabase;
+    /// use r2d2_adbc::AdbcConnectionManager;
+    ///
+    /// let manager: AdbcConnectionManager<ManagedDatabase> = AdbcConnectionManager::from_driver(
+    ///     "adbc_driver_sqlite",
+    ///     vec![("uri".to_string(), "file::memory:".to_string())],
+    /// );
+    /// let pool = r2d2::Pool::builder().build(manager).unwrap();
+    /// ```
+    pub fn from_driver<I>(driver: impl Into<String>, options: I) -> Self
+    where
+        I: IntoIterator<Item = (String, String)>,
+    {
+        let driver = driver.into();
+        let options: Vec<(String, String)> = options.into_iter().collect();
+
+        let init = move || -> Result<D, AdbcError> {
+            let mut driver_impl =
+                ManagedDriver::load_dynamic_from_name(&driver, None, AdbcVersion::V110)
+                    .map_err(AdbcError::from)?;
+            driver_impl
+                .new_database_with_opts(
+                    options
+                        .iter()
+                        .map(|(k, v)| (OptionDatabase::from(k.as_str()), OptionValue::from(v.as_str()))),
+                )
+                .map(D::from_managed)
+                .map_err(AdbcError::from)
+        };
+
+        Self {
+            database: DatabaseSource::Lazy(OnceLock::new(), Box::new(init)),
+            connection_options: Vec::new(),
+            customizer: None,
+            reset_on_return: true,
+            default_autocommit: true,
+            test_query: None,
+        }
+    }
+}
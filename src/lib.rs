@@ -44,9 +44,15 @@
 //! ```
 
 use adbc_core::options::{OptionConnection, OptionValue};
-use adbc_core::{Connection, Database};
+use adbc_core::{Connection, Database, Optionable, Statement};
 use std::error::Error as StdError;
 use std::fmt;
+use std::sync::OnceLock;
+
+#[cfg(feature = "driver-manager")]
+pub(crate) mod driver_manager;
+#[cfg(feature = "rocket")]
+pub mod rocket;
 
 /// An r2d2 connection manager for ADBC connections.
 ///
@@ -62,8 +68,43 @@ pub struct AdbcConnectionManager<D>
 where
     D: Database,
 {
-    database: D,
+    database: DatabaseSource<D>,
     connection_options: Vec<(String, String)>,
+    customizer: Option<Box<dyn AdbcConnectionCustomizer<D::ConnectionType>>>,
+    reset_on_return: bool,
+    default_autocommit: bool,
+    test_query: Option<String>,
+}
+
+/// Where an `AdbcConnectionManager` gets its `Database` from.
+///
+/// Most managers are built from a `Database` the caller already has in hand
+/// ([`Ready`](Self::Ready)). The `driver-manager` feature's
+/// `AdbcConnectionManager::from_driver` instead stores the driver/option spec
+/// needed to build one and defers construction until the first connection is
+/// requested ([`Lazy`](Self::Lazy)), since building a `Database` can fail and
+/// that failure needs to surface through `ManageConnection::connect` rather
+/// than the constructor.
+enum DatabaseSource<D> {
+    Ready(D),
+    #[cfg_attr(not(feature = "driver-manager"), allow(dead_code))]
+    Lazy(OnceLock<D>, Box<dyn Fn() -> Result<D, AdbcError> + Send + Sync>),
+}
+
+impl<D> DatabaseSource<D> {
+    fn get(&self) -> Result<&D, AdbcError> {
+        match self {
+            DatabaseSource::Ready(database) => Ok(database),
+            DatabaseSource::Lazy(cell, init) => {
+                if let Some(database) = cell.get() {
+                    return Ok(database);
+                }
+                let database = init()?;
+                let _ = cell.set(database);
+                Ok(cell.get().expect("database was just initialized above"))
+            }
+        }
+    }
 }
 
 impl<D> AdbcConnectionManager<D>
@@ -90,8 +131,12 @@ where
     /// ```
     pub fn new(database: D) -> Self {
         Self {
-            database,
+            database: DatabaseSource::Ready(database),
             connection_options: Vec::new(),
+            customizer: None,
+            reset_on_return: true,
+            default_autocommit: true,
+            test_query: None,
         }
     }
 
@@ -123,11 +168,102 @@ where
         I: IntoIterator<Item = (String, String)>,
     {
         Self {
-            database,
+            database: DatabaseSource::Ready(database),
             connection_options: options.into_iter().collect(),
+            customizer: None,
+            reset_on_return: true,
+            default_autocommit: true,
+            test_query: None,
         }
     }
 
+    /// Sets the connection customizer used to run per-connection setup and
+    /// teardown hooks.
+    ///
+    /// The customizer's [`AdbcConnectionCustomizer::on_acquire`] method runs
+    /// once right after a connection is created, before it is handed out by
+    /// the pool, and its [`AdbcConnectionCustomizer::on_release`] method runs
+    /// when the connection is returned. This is the escape hatch for
+    /// initialization that can't be expressed as a static key/value option in
+    /// [`connection_options`](Self::options), such as setting session
+    /// variables or warming a cache.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use r2d2_adbc::{AdbcConnectionCustomizer, AdbcConnectionManager, AdbcError};
+    /// # use adbc_core::{Connection, Database};
+    ///
+    /// struct SetSearchPath;
+    ///
+    /// impl<C: Connection> AdbcConnectionCustomizer<C> for SetSearchPath {
+    ///     fn on_acquire(&self, conn: &mut C) -> Result<(), AdbcError> {
+    ///         // e.g. conn.set_option(...) to configure the session
+    ///         Ok(())
+    ///     }
+    /// }
+    ///
+    /// # fn example<D: Database>(database: D) {
+    /// let manager = AdbcConnectionManager::new(database).with_customizer(SetSearchPath);
+    /// # }
+    /// ```
+    pub fn with_customizer(
+        mut self,
+        customizer: impl AdbcConnectionCustomizer<D::ConnectionType> + 'static,
+    ) -> Self {
+        self.customizer = Some(Box::new(customizer));
+        self
+    }
+
+    /// Controls whether a connection returned to the pool with an open
+    /// transaction has that transaction rolled back and autocommit restored
+    /// before it can be reused.
+    ///
+    /// Defaults to `true`. Without this, a connection checked in mid-transaction
+    /// would silently poison the next borrower. Pools used for read-only
+    /// workloads that keep every connection in autocommit the whole time can
+    /// set this to `false` to skip the extra round trip on check-in.
+    pub fn with_reset_on_return(mut self, reset_on_return: bool) -> Self {
+        self.reset_on_return = reset_on_return;
+        self
+    }
+
+    /// Sets the autocommit mode that `reset_on_return` puts a connection back
+    /// into on check-in, after rolling back any open transaction. A connection
+    /// already in this mode is left as it is.
+    ///
+    /// Defaults to `true`, matching the ADBC default. Set to `false` for
+    /// pools whose connections should always begin a fresh transaction
+    /// between borrows instead of running in autocommit.
+    pub fn with_default_autocommit(mut self, default_autocommit: bool) -> Self {
+        self.default_autocommit = default_autocommit;
+        self
+    }
+
+    /// Sets a validation query that `is_valid` executes against the backend,
+    /// trading validation cost for reliability.
+    ///
+    /// Without a test query, `is_valid` only creates a statement locally,
+    /// which does not detect a dead socket for drivers where statement
+    /// creation never reaches the server. When set, `is_valid` prepares and
+    /// fully executes this query and drains the returned stream on every
+    /// check-out, forcing a real round trip.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use r2d2_adbc::AdbcConnectionManager;
+    /// # use adbc_core::Database;
+    ///
+    /// # fn example<D: Database>(database: D) {
+    /// let manager = AdbcConnectionManager::new(database).with_test_query("SELECT 1");
+    /// # }
+    /// ```
+    pub fn with_test_query(mut self, query: impl Into<String>) -> Self {
+        self.test_query = Some(query.into());
+        self
+    }
+
     /// Adds a connection option that will be applied to all new connections.
     ///
     /// # Arguments
@@ -161,6 +297,30 @@ where
     }
 }
 
+/// A hook for running custom setup and teardown logic on pooled ADBC
+/// connections.
+///
+/// This mirrors diesel's `CustomizeConnection` trait: implement
+/// [`on_acquire`](Self::on_acquire) to run arbitrary initialization each time
+/// a connection is created (for example, setting session variables via
+/// [`Connection::set_option`], registering temp tables, or warming caches)
+/// and [`on_release`](Self::on_release) to clean up when the connection is
+/// returned to the pool. Both methods have no-op default implementations, so
+/// implementors only need to override the one they care about.
+pub trait AdbcConnectionCustomizer<C>: Send + Sync {
+    /// Called immediately after a new connection is created, before it is
+    /// handed out by the pool.
+    fn on_acquire(&self, conn: &mut C) -> Result<(), AdbcError> {
+        let _ = conn;
+        Ok(())
+    }
+
+    /// Called when a connection is returned to the pool.
+    fn on_release(&self, conn: &mut C) {
+        let _ = conn;
+    }
+}
+
 /// Error wrapper for ADBC errors in the r2d2 context.
 ///
 /// This type wraps the ADBC error type to provide a consistent error
@@ -186,6 +346,50 @@ impl From<adbc_core::error::Error> for AdbcError {
     }
 }
 
+impl AdbcError {
+    /// Returns the ADBC status code the driver reported for this error.
+    pub fn status(&self) -> adbc_core::error::Status {
+        self.0.status
+    }
+
+    /// Returns the five-character SQLSTATE code the driver attached to this
+    /// error, if any.
+    pub fn sqlstate(&self) -> Option<String> {
+        let sqlstate = self.0.sqlstate.map(|b| b as u8);
+        if sqlstate == [0; 5] {
+            None
+        } else {
+            Some(String::from_utf8_lossy(&sqlstate).into_owned())
+        }
+    }
+
+    /// Returns the driver-specific vendor error code attached to this error.
+    ///
+    /// A value of `0` means the driver did not report one.
+    pub fn vendor_code(&self) -> i32 {
+        self.0.vendor_code
+    }
+
+    /// Returns any additional structured error details the driver attached,
+    /// as raw `(key, value)` pairs.
+    pub fn error_details(&self) -> &[(String, Vec<u8>)] {
+        self.0.details.as_deref().unwrap_or(&[])
+    }
+
+    /// Returns whether this error looks like a transient failure a caller
+    /// might want to retry (e.g. with backoff) rather than a permanent
+    /// failure such as an authorization or schema error.
+    ///
+    /// This is a coarse mapping of [`status`](Self::status) and is meant as
+    /// a starting point for pool users implementing retry logic on
+    /// check-out failures, not an exhaustive classification.
+    pub fn is_retryable(&self) -> bool {
+        use adbc_core::error::Status;
+
+        matches!(self.0.status, Status::IO | Status::Timeout | Status::Cancelled)
+    }
+}
+
 impl<D> r2d2::ManageConnection for AdbcConnectionManager<D>
 where
     D: Database + Send + Sync + 'static,
@@ -197,39 +401,84 @@ where
     /// Creates a new connection using the underlying ADBC database.
     ///
     /// If connection options were provided, they will be passed to the connection
-    /// during initialization.
+    /// during initialization. If a [`AdbcConnectionCustomizer`] was configured via
+    /// [`AdbcConnectionManager::with_customizer`], its `on_acquire` hook runs last.
     fn connect(&self) -> Result<Self::Connection, Self::Error> {
-        if self.connection_options.is_empty() {
-            self.database.new_connection().map_err(AdbcError::from)
+        let database = self.database.get()?;
+
+        let mut conn = if self.connection_options.is_empty() {
+            database.new_connection().map_err(AdbcError::from)?
         } else {
-            self.database
+            database
                 .new_connection_with_opts(
                     self.connection_options
                         .iter()
                         .map(|(k, v)| (OptionConnection::from(k.as_str()), OptionValue::from(v.as_str()))),
                 )
-                .map_err(AdbcError::from)
+                .map_err(AdbcError::from)?
+        };
+
+        if let Some(customizer) = &self.customizer {
+            customizer.on_acquire(&mut conn)?;
         }
+
+        Ok(conn)
     }
 
     /// Validates that the connection is still functional.
     ///
-    /// This performs a lightweight check by attempting to create a new statement.
-    /// If statement creation succeeds, the connection is considered valid.
+    /// If a validation query was configured via
+    /// [`AdbcConnectionManager::with_test_query`], this prepares and executes
+    /// it against the backend and drains the returned Arrow stream, forcing a
+    /// round trip to the server. This catches a dead connection that a purely
+    /// local check would miss (e.g. for drivers such as Flight SQL, where
+    /// creating a statement never touches the network). Otherwise this falls
+    /// back to the cheap check of attempting to create a new statement.
     fn is_valid(&self, conn: &mut Self::Connection) -> Result<(), Self::Error> {
-        // Attempt to create a statement as a validation check
-        // If this succeeds, the connection is considered valid
-        conn.new_statement().map(|_| ()).map_err(AdbcError::from)
+        let Some(query) = &self.test_query else {
+            // Attempt to create a statement as a validation check
+            // If this succeeds, the connection is considered valid
+            return conn.new_statement().map(|_| ()).map_err(AdbcError::from);
+        };
+
+        let mut statement = conn.new_statement().map_err(AdbcError::from)?;
+        statement.set_sql_query(query).map_err(AdbcError::from)?;
+        let reader = statement.execute().map_err(AdbcError::from)?;
+
+        for batch in reader {
+            batch.map_err(|err| {
+                AdbcError(adbc_core::error::Error::with_message_and_status(
+                    err.to_string(),
+                    adbc_core::error::Status::IO,
+                ))
+            })?;
+        }
+
+        Ok(())
     }
 
     /// Performs a quick check to determine if the connection has been broken.
     ///
     /// This is a fast, synchronous check that returns `false` to indicate
-    /// the connection should be tested further with `is_valid`.
+    /// the connection should be tested further with `is_valid`. r2d2 calls
+    /// this when a connection is returned to the pool, so if a
+    /// [`AdbcConnectionCustomizer`] was configured, its `on_release` hook
+    /// runs here.
     ///
     /// Note: ADBC connections don't provide a lightweight broken state check,
     /// so this always returns `false` to defer to the `is_valid` check.
-    fn has_broken(&self, _conn: &mut Self::Connection) -> bool {
+    fn has_broken(&self, conn: &mut Self::Connection) -> bool {
+        if let Some(customizer) = &self.customizer {
+            customizer.on_release(conn);
+        }
+
+        if self.reset_on_return && self.reset_connection(conn).is_err() {
+            // The connection couldn't be safely reset (e.g. the rollback
+            // failed), so report it as broken rather than hand a connection
+            // with an uncertain transaction state to the next borrower.
+            return true;
+        }
+
         // ADBC connections don't have a lightweight way to check if they're broken
         // without actually trying to use them, so we return false here and rely
         // on is_valid() to do the actual validation
@@ -237,6 +486,34 @@ where
     }
 }
 
+impl<D> AdbcConnectionManager<D>
+where
+    D: Database,
+{
+    /// Rolls back any transaction left open on a connection being returned to
+    /// the pool and restores the configured default autocommit mode.
+    fn reset_connection(&self, conn: &mut D::ConnectionType) -> Result<(), AdbcError> {
+        let default_autocommit = if self.default_autocommit { "true" } else { "false" };
+        let autocommit = conn.get_option_string(OptionConnection::AutoCommit)?;
+
+        if autocommit == "false" {
+            conn.rollback()?;
+        }
+
+        // Skip the round trip when the connection is already at the
+        // configured default; pools that never open a transaction would
+        // otherwise pay it on every single check-in.
+        if autocommit != default_autocommit {
+            conn.set_option(
+                OptionConnection::AutoCommit,
+                OptionValue::String(default_autocommit.to_string()),
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -264,4 +541,514 @@ mod tests {
 
         assert!(wrapped_err.source().is_some());
     }
+
+    #[test]
+    fn test_sqlstate_unset() {
+        use adbc_core::error::{Error, Status};
+
+        let adbc_err = Error::with_message_and_status("test error", Status::Internal);
+        let wrapped_err = AdbcError(adbc_err);
+
+        assert_eq!(wrapped_err.sqlstate(), None);
+    }
+
+    #[test]
+    fn test_sqlstate_set() {
+        use adbc_core::error::{Error, Status};
+
+        let mut adbc_err = Error::with_message_and_status("test error", Status::Internal);
+        adbc_err.sqlstate = [b'2' as _, b'3' as _, b'5' as _, b'0' as _, b'5' as _];
+        let wrapped_err = AdbcError(adbc_err);
+
+        assert_eq!(wrapped_err.sqlstate(), Some("23505".to_string()));
+    }
+
+    #[test]
+    fn test_vendor_code() {
+        use adbc_core::error::{Error, Status};
+
+        let mut adbc_err = Error::with_message_and_status("test error", Status::Internal);
+        adbc_err.vendor_code = 42;
+        let wrapped_err = AdbcError(adbc_err);
+
+        assert_eq!(wrapped_err.vendor_code(), 42);
+    }
+
+    #[test]
+    fn test_error_details() {
+        use adbc_core::error::{Error, Status};
+
+        let mut adbc_err = Error::with_message_and_status("test error", Status::Internal);
+        adbc_err.details = Some(vec![("key".to_string(), vec![1, 2, 3])]);
+        let wrapped_err = AdbcError(adbc_err);
+
+        assert_eq!(
+            wrapped_err.error_details(),
+            &[("key".to_string(), vec![1, 2, 3])]
+        );
+    }
+
+    #[test]
+    fn test_error_details_empty_when_unset() {
+        use adbc_core::error::{Error, Status};
+
+        let adbc_err = Error::with_message_and_status("test error", Status::Internal);
+        let wrapped_err = AdbcError(adbc_err);
+
+        assert!(wrapped_err.error_details().is_empty());
+    }
+
+    #[test]
+    fn test_is_retryable_true_for_transient_statuses() {
+        use adbc_core::error::{Error, Status};
+
+        for status in [Status::IO, Status::Timeout, Status::Cancelled] {
+            let wrapped_err = AdbcError(Error::with_message_and_status("test error", status));
+            assert!(wrapped_err.is_retryable());
+        }
+    }
+
+    #[test]
+    fn test_is_retryable_false_for_permanent_statuses() {
+        use adbc_core::error::{Error, Status};
+
+        for status in [Status::Internal, Status::Unauthorized, Status::NotFound] {
+            let wrapped_err = AdbcError(Error::with_message_and_status("test error", status));
+            assert!(!wrapped_err.is_retryable());
+        }
+    }
+
+    /// Minimal in-crate `Database`/`Connection`/`Statement` mocks for exercising
+    /// pool lifecycle behavior without a real ADBC driver. Only the paths the
+    /// tests in this module exercise are implemented; everything else panics.
+    mod mock {
+        use super::*;
+        use adbc_core::error::Result as AdbcResult;
+        use adbc_core::options::{InfoCode, ObjectDepth, OptionDatabase, OptionStatement};
+        use adbc_core::PartitionedResult;
+        use arrow_array::{RecordBatch, RecordBatchIterator, RecordBatchReader};
+        use arrow_schema::Schema;
+        use std::collections::HashSet;
+        use std::sync::{Arc, Mutex};
+
+        #[derive(Debug, Default)]
+        pub struct State {
+            pub autocommit: Mutex<String>,
+            pub rolled_back: Mutex<bool>,
+            pub set_option_calls: Mutex<usize>,
+            pub statements_created: Mutex<usize>,
+            pub last_query: Mutex<Option<String>>,
+            pub execute_calls: Mutex<usize>,
+        }
+
+        impl State {
+            pub fn new(autocommit: &str) -> Arc<Self> {
+                Arc::new(Self {
+                    autocommit: Mutex::new(autocommit.to_string()),
+                    ..Default::default()
+                })
+            }
+        }
+
+        pub struct MockDatabase(pub Arc<State>);
+
+        impl Optionable for MockDatabase {
+            type Option = OptionDatabase;
+
+            fn set_option(&mut self, _key: Self::Option, _value: OptionValue) -> AdbcResult<()> {
+                unimplemented!()
+            }
+
+            fn get_option_string(&self, _key: Self::Option) -> AdbcResult<String> {
+                unimplemented!()
+            }
+
+            fn get_option_bytes(&self, _key: Self::Option) -> AdbcResult<Vec<u8>> {
+                unimplemented!()
+            }
+
+            fn get_option_int(&self, _key: Self::Option) -> AdbcResult<i64> {
+                unimplemented!()
+            }
+
+            fn get_option_double(&self, _key: Self::Option) -> AdbcResult<f64> {
+                unimplemented!()
+            }
+        }
+
+        impl Database for MockDatabase {
+            type ConnectionType = MockConnection;
+
+            fn new_connection(&self) -> AdbcResult<Self::ConnectionType> {
+                Ok(MockConnection(self.0.clone()))
+            }
+
+            fn new_connection_with_opts(
+                &self,
+                _opts: impl IntoIterator<Item = (OptionConnection, OptionValue)>,
+            ) -> AdbcResult<Self::ConnectionType> {
+                Ok(MockConnection(self.0.clone()))
+            }
+        }
+
+        #[derive(Debug)]
+        pub struct MockConnection(pub Arc<State>);
+
+        impl Optionable for MockConnection {
+            type Option = OptionConnection;
+
+            fn set_option(&mut self, key: Self::Option, value: OptionValue) -> AdbcResult<()> {
+                *self.0.set_option_calls.lock().unwrap() += 1;
+                if key == OptionConnection::AutoCommit {
+                    if let OptionValue::String(value) = value {
+                        *self.0.autocommit.lock().unwrap() = value;
+                    }
+                }
+                Ok(())
+            }
+
+            fn get_option_string(&self, key: Self::Option) -> AdbcResult<String> {
+                if key == OptionConnection::AutoCommit {
+                    Ok(self.0.autocommit.lock().unwrap().clone())
+                } else {
+                    unimplemented!()
+                }
+            }
+
+            fn get_option_bytes(&self, _key: Self::Option) -> AdbcResult<Vec<u8>> {
+                unimplemented!()
+            }
+
+            fn get_option_int(&self, _key: Self::Option) -> AdbcResult<i64> {
+                unimplemented!()
+            }
+
+            fn get_option_double(&self, _key: Self::Option) -> AdbcResult<f64> {
+                unimplemented!()
+            }
+        }
+
+        impl Connection for MockConnection {
+            type StatementType = MockStatement;
+
+            fn new_statement(&mut self) -> AdbcResult<Self::StatementType> {
+                *self.0.statements_created.lock().unwrap() += 1;
+                Ok(MockStatement(self.0.clone()))
+            }
+
+            fn cancel(&mut self) -> AdbcResult<()> {
+                unimplemented!()
+            }
+
+            fn get_info(
+                &self,
+                _codes: Option<HashSet<InfoCode>>,
+            ) -> AdbcResult<Box<dyn RecordBatchReader + Send + 'static>> {
+                unimplemented!()
+            }
+
+            fn get_objects(
+                &self,
+                _depth: ObjectDepth,
+                _catalog: Option<&str>,
+                _db_schema: Option<&str>,
+                _table_name: Option<&str>,
+                _table_type: Option<Vec<&str>>,
+                _column_name: Option<&str>,
+            ) -> AdbcResult<Box<dyn RecordBatchReader + Send + 'static>> {
+                unimplemented!()
+            }
+
+            fn get_table_schema(
+                &self,
+                _catalog: Option<&str>,
+                _db_schema: Option<&str>,
+                _table_name: &str,
+            ) -> AdbcResult<Schema> {
+                unimplemented!()
+            }
+
+            fn get_table_types(&self) -> AdbcResult<Box<dyn RecordBatchReader + Send + 'static>> {
+                unimplemented!()
+            }
+
+            fn get_statistic_names(&self) -> AdbcResult<Box<dyn RecordBatchReader + Send + 'static>> {
+                unimplemented!()
+            }
+
+            fn get_statistics(
+                &self,
+                _catalog: Option<&str>,
+                _db_schema: Option<&str>,
+                _table_name: Option<&str>,
+                _approximate: bool,
+            ) -> AdbcResult<Box<dyn RecordBatchReader + Send + 'static>> {
+                unimplemented!()
+            }
+
+            fn commit(&mut self) -> AdbcResult<()> {
+                unimplemented!()
+            }
+
+            fn rollback(&mut self) -> AdbcResult<()> {
+                *self.0.rolled_back.lock().unwrap() = true;
+                Ok(())
+            }
+
+            fn read_partition(
+                &self,
+                _partition: impl AsRef<[u8]>,
+            ) -> AdbcResult<Box<dyn RecordBatchReader + Send + 'static>> {
+                unimplemented!()
+            }
+        }
+
+        pub struct MockStatement(pub Arc<State>);
+
+        impl Optionable for MockStatement {
+            type Option = OptionStatement;
+
+            fn set_option(&mut self, _key: Self::Option, _value: OptionValue) -> AdbcResult<()> {
+                unimplemented!()
+            }
+
+            fn get_option_string(&self, _key: Self::Option) -> AdbcResult<String> {
+                unimplemented!()
+            }
+
+            fn get_option_bytes(&self, _key: Self::Option) -> AdbcResult<Vec<u8>> {
+                unimplemented!()
+            }
+
+            fn get_option_int(&self, _key: Self::Option) -> AdbcResult<i64> {
+                unimplemented!()
+            }
+
+            fn get_option_double(&self, _key: Self::Option) -> AdbcResult<f64> {
+                unimplemented!()
+            }
+        }
+
+        impl Statement for MockStatement {
+            fn bind(&mut self, _batch: RecordBatch) -> AdbcResult<()> {
+                unimplemented!()
+            }
+
+            fn bind_stream(&mut self, _reader: Box<dyn RecordBatchReader + Send>) -> AdbcResult<()> {
+                unimplemented!()
+            }
+
+            fn execute(&mut self) -> AdbcResult<Box<dyn RecordBatchReader + Send + 'static>> {
+                *self.0.execute_calls.lock().unwrap() += 1;
+                let schema = Arc::new(Schema::empty());
+                Ok(Box::new(RecordBatchIterator::new(std::iter::empty(), schema)))
+            }
+
+            fn execute_update(&mut self) -> AdbcResult<Option<i64>> {
+                unimplemented!()
+            }
+
+            fn execute_schema(&mut self) -> AdbcResult<Schema> {
+                unimplemented!()
+            }
+
+            fn execute_partitions(&mut self) -> AdbcResult<PartitionedResult> {
+                unimplemented!()
+            }
+
+            fn get_parameter_schema(&self) -> AdbcResult<Schema> {
+                unimplemented!()
+            }
+
+            fn prepare(&mut self) -> AdbcResult<()> {
+                unimplemented!()
+            }
+
+            fn set_sql_query(&mut self, query: impl AsRef<str>) -> AdbcResult<()> {
+                *self.0.last_query.lock().unwrap() = Some(query.as_ref().to_string());
+                Ok(())
+            }
+
+            fn set_substrait_plan(&mut self, _plan: impl AsRef<[u8]>) -> AdbcResult<()> {
+                unimplemented!()
+            }
+
+            fn cancel(&mut self) -> AdbcResult<()> {
+                unimplemented!()
+            }
+        }
+    }
+
+    #[test]
+    fn test_customizer_on_acquire_runs_during_connect() {
+        use r2d2::ManageConnection;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Arc;
+
+        struct CountAcquire(Arc<AtomicUsize>);
+
+        impl AdbcConnectionCustomizer<mock::MockConnection> for CountAcquire {
+            fn on_acquire(&self, _conn: &mut mock::MockConnection) -> Result<(), AdbcError> {
+                self.0.fetch_add(1, Ordering::SeqCst);
+                Ok(())
+            }
+        }
+
+        let acquired = Arc::new(AtomicUsize::new(0));
+        let database = mock::MockDatabase(mock::State::new("true"));
+        let manager =
+            AdbcConnectionManager::new(database).with_customizer(CountAcquire(acquired.clone()));
+
+        manager.connect().expect("connect should succeed");
+
+        assert_eq!(acquired.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_customizer_on_release_runs_during_has_broken() {
+        use r2d2::ManageConnection;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Arc;
+
+        struct CountRelease(Arc<AtomicUsize>);
+
+        impl AdbcConnectionCustomizer<mock::MockConnection> for CountRelease {
+            fn on_release(&self, _conn: &mut mock::MockConnection) {
+                self.0.fetch_add(1, Ordering::SeqCst);
+            }
+        }
+
+        let released = Arc::new(AtomicUsize::new(0));
+        let database = mock::MockDatabase(mock::State::new("true"));
+        let manager = AdbcConnectionManager::new(database)
+            .with_customizer(CountRelease(released.clone()))
+            .with_reset_on_return(false);
+        let mut conn = manager.connect().expect("connect should succeed");
+
+        manager.has_broken(&mut conn);
+
+        assert_eq!(released.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_lazy_database_initializes_once_and_caches() {
+        use r2d2::ManageConnection;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Arc;
+
+        let init_calls = Arc::new(AtomicUsize::new(0));
+        let init_calls_for_closure = init_calls.clone();
+        let state = mock::State::new("true");
+
+        let manager = AdbcConnectionManager {
+            database: DatabaseSource::Lazy(
+                OnceLock::new(),
+                Box::new(move || {
+                    init_calls_for_closure.fetch_add(1, Ordering::SeqCst);
+                    Ok(mock::MockDatabase(state.clone()))
+                }),
+            ),
+            connection_options: Vec::new(),
+            customizer: None,
+            reset_on_return: true,
+            default_autocommit: true,
+            test_query: None,
+        };
+
+        manager.connect().expect("first connect should succeed");
+        manager.connect().expect("second connect should succeed");
+
+        assert_eq!(init_calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_lazy_database_init_error_surfaces_from_connect() {
+        use r2d2::ManageConnection;
+
+        let manager: AdbcConnectionManager<mock::MockDatabase> = AdbcConnectionManager {
+            database: DatabaseSource::Lazy(
+                OnceLock::new(),
+                Box::new(|| {
+                    Err(AdbcError(adbc_core::error::Error::with_message_and_status(
+                        "driver not found",
+                        adbc_core::error::Status::NotFound,
+                    )))
+                }),
+            ),
+            connection_options: Vec::new(),
+            customizer: None,
+            reset_on_return: true,
+            default_autocommit: true,
+            test_query: None,
+        };
+
+        let err = manager
+            .connect()
+            .expect_err("connect should surface the init failure");
+        assert_eq!(err.status(), adbc_core::error::Status::NotFound);
+    }
+
+    #[test]
+    fn test_reset_connection_skips_round_trip_at_default_autocommit() {
+        let state = mock::State::new("true");
+        let database = mock::MockDatabase(state.clone());
+        let manager = AdbcConnectionManager::new(database);
+
+        let mut conn = mock::MockConnection(state.clone());
+        manager
+            .reset_connection(&mut conn)
+            .expect("reset should succeed");
+
+        assert!(!*state.rolled_back.lock().unwrap());
+        assert_eq!(*state.set_option_calls.lock().unwrap(), 0);
+    }
+
+    #[test]
+    fn test_reset_connection_rolls_back_and_restores_autocommit_when_off() {
+        let state = mock::State::new("false");
+        let database = mock::MockDatabase(state.clone());
+        let manager = AdbcConnectionManager::new(database);
+
+        let mut conn = mock::MockConnection(state.clone());
+        manager
+            .reset_connection(&mut conn)
+            .expect("reset should succeed");
+
+        assert!(*state.rolled_back.lock().unwrap());
+        assert_eq!(*state.set_option_calls.lock().unwrap(), 1);
+        assert_eq!(*state.autocommit.lock().unwrap(), "true");
+    }
+
+    #[test]
+    fn test_is_valid_executes_and_drains_configured_test_query() {
+        use r2d2::ManageConnection;
+
+        let state = mock::State::new("true");
+        let database = mock::MockDatabase(state.clone());
+        let manager = AdbcConnectionManager::new(database).with_test_query("SELECT 1");
+
+        let mut conn = manager.connect().expect("connect should succeed");
+        manager.is_valid(&mut conn).expect("is_valid should succeed");
+
+        assert_eq!(
+            *state.last_query.lock().unwrap(),
+            Some("SELECT 1".to_string())
+        );
+        assert_eq!(*state.execute_calls.lock().unwrap(), 1);
+    }
+
+    #[test]
+    fn test_is_valid_falls_back_to_statement_creation_without_test_query() {
+        use r2d2::ManageConnection;
+
+        let state = mock::State::new("true");
+        let database = mock::MockDatabase(state.clone());
+        let manager = AdbcConnectionManager::new(database);
+
+        let mut conn = manager.connect().expect("connect should succeed");
+        manager.is_valid(&mut conn).expect("is_valid should succeed");
+
+        assert_eq!(*state.statements_created.lock().unwrap(), 1);
+        assert_eq!(*state.execute_calls.lock().unwrap(), 0);
+    }
 }
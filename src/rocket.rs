@@ -0,0 +1,257 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Rocket integration: implements [`rocket_sync_db_pools::Poolable`] for ADBC
+//! connections so a Rocket application can declare an ADBC-backed database in
+//! its config and have Rocket provision and inject the pool automatically.
+//!
+//! ```toml
+//! [default.databases.adbc]
+//! url = "adbc_driver_sqlite"
+//!
+//! [default.databases.adbc.options]
+//! uri = "file::memory:"
+//! ```
+//!
+//! ```no_run
+//! # #[macro_use] extern crate rocket_sync_db_pools;
+//! use r2d2_adbc::rocket::AdbcRocketConnection;
+//!
+//! #[database("adbc")]
+//! struct Db(AdbcRocketConnection);
+//! ```
+
+use std::collections::{HashMap, HashSet};
+use std::ops::{Deref, DerefMut};
+use std::time::Duration;
+
+use adbc_core::error::Result as AdbcResult;
+use adbc_core::options::{self, OptionConnection, OptionDatabase, OptionValue};
+use adbc_core::{Connection, Database, Optionable};
+use adbc_driver_manager::{ManagedConnection, ManagedDatabase};
+use arrow_array::RecordBatchReader;
+use arrow_schema::Schema;
+use rocket_sync_db_pools::{
+    r2d2,
+    rocket::{Build, Rocket},
+    Config, PoolResult, Poolable,
+};
+
+use crate::driver_manager::FromManagedDatabase;
+use crate::AdbcConnectionManager;
+
+/// A [`ManagedDatabase`] newtype that [`Poolable`] can be implemented for.
+///
+/// Rust's orphan rules forbid implementing a foreign trait (`Poolable`) for a
+/// foreign type (`ManagedConnection`) directly, so this crate wraps both the
+/// database and connection ends in local types instead. It delegates every
+/// operation straight to the wrapped `ManagedDatabase`.
+pub struct AdbcRocketDatabase(ManagedDatabase);
+
+impl FromManagedDatabase for AdbcRocketDatabase {
+    fn from_managed(database: ManagedDatabase) -> Self {
+        Self(database)
+    }
+}
+
+impl Optionable for AdbcRocketDatabase {
+    type Option = OptionDatabase;
+
+    fn set_option(&mut self, key: Self::Option, value: OptionValue) -> AdbcResult<()> {
+        self.0.set_option(key, value)
+    }
+
+    fn get_option_string(&self, key: Self::Option) -> AdbcResult<String> {
+        self.0.get_option_string(key)
+    }
+
+    fn get_option_bytes(&self, key: Self::Option) -> AdbcResult<Vec<u8>> {
+        self.0.get_option_bytes(key)
+    }
+
+    fn get_option_int(&self, key: Self::Option) -> AdbcResult<i64> {
+        self.0.get_option_int(key)
+    }
+
+    fn get_option_double(&self, key: Self::Option) -> AdbcResult<f64> {
+        self.0.get_option_double(key)
+    }
+}
+
+impl Database for AdbcRocketDatabase {
+    type ConnectionType = AdbcRocketConnection;
+
+    fn new_connection(&self) -> AdbcResult<Self::ConnectionType> {
+        self.0.new_connection().map(AdbcRocketConnection)
+    }
+
+    fn new_connection_with_opts(
+        &self,
+        opts: impl IntoIterator<Item = (OptionConnection, OptionValue)>,
+    ) -> AdbcResult<Self::ConnectionType> {
+        self.0
+            .new_connection_with_opts(opts)
+            .map(AdbcRocketConnection)
+    }
+}
+
+/// The connection type backing [`AdbcRocketDatabase`]; see its docs for why
+/// this wrapper exists. Derefs to the underlying [`ManagedConnection`] so
+/// driver-specific extensions remain reachable.
+pub struct AdbcRocketConnection(ManagedConnection);
+
+impl Deref for AdbcRocketConnection {
+    type Target = ManagedConnection;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for AdbcRocketConnection {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl Optionable for AdbcRocketConnection {
+    type Option = OptionConnection;
+
+    fn set_option(&mut self, key: Self::Option, value: OptionValue) -> AdbcResult<()> {
+        self.0.set_option(key, value)
+    }
+
+    fn get_option_string(&self, key: Self::Option) -> AdbcResult<String> {
+        self.0.get_option_string(key)
+    }
+
+    fn get_option_bytes(&self, key: Self::Option) -> AdbcResult<Vec<u8>> {
+        self.0.get_option_bytes(key)
+    }
+
+    fn get_option_int(&self, key: Self::Option) -> AdbcResult<i64> {
+        self.0.get_option_int(key)
+    }
+
+    fn get_option_double(&self, key: Self::Option) -> AdbcResult<f64> {
+        self.0.get_option_double(key)
+    }
+}
+
+impl Connection for AdbcRocketConnection {
+    type StatementType = <ManagedConnection as Connection>::StatementType;
+
+    fn new_statement(&mut self) -> AdbcResult<Self::StatementType> {
+        self.0.new_statement()
+    }
+
+    fn cancel(&mut self) -> AdbcResult<()> {
+        self.0.cancel()
+    }
+
+    fn get_info(
+        &self,
+        codes: Option<HashSet<options::InfoCode>>,
+    ) -> AdbcResult<Box<dyn RecordBatchReader + Send + 'static>> {
+        self.0.get_info(codes)
+    }
+
+    fn get_objects(
+        &self,
+        depth: options::ObjectDepth,
+        catalog: Option<&str>,
+        db_schema: Option<&str>,
+        table_name: Option<&str>,
+        table_type: Option<Vec<&str>>,
+        column_name: Option<&str>,
+    ) -> AdbcResult<Box<dyn RecordBatchReader + Send + 'static>> {
+        self.0
+            .get_objects(depth, catalog, db_schema, table_name, table_type, column_name)
+    }
+
+    fn get_table_schema(
+        &self,
+        catalog: Option<&str>,
+        db_schema: Option<&str>,
+        table_name: &str,
+    ) -> AdbcResult<Schema> {
+        self.0.get_table_schema(catalog, db_schema, table_name)
+    }
+
+    fn get_table_types(&self) -> AdbcResult<Box<dyn RecordBatchReader + Send + 'static>> {
+        self.0.get_table_types()
+    }
+
+    fn get_statistic_names(&self) -> AdbcResult<Box<dyn RecordBatchReader + Send + 'static>> {
+        self.0.get_statistic_names()
+    }
+
+    fn get_statistics(
+        &self,
+        catalog: Option<&str>,
+        db_schema: Option<&str>,
+        table_name: Option<&str>,
+        approximate: bool,
+    ) -> AdbcResult<Box<dyn RecordBatchReader + Send + 'static>> {
+        self.0
+            .get_statistics(catalog, db_schema, table_name, approximate)
+    }
+
+    fn commit(&mut self) -> AdbcResult<()> {
+        self.0.commit()
+    }
+
+    fn rollback(&mut self) -> AdbcResult<()> {
+        self.0.rollback()
+    }
+
+    fn read_partition(
+        &self,
+        partition: impl AsRef<[u8]>,
+    ) -> AdbcResult<Box<dyn RecordBatchReader + Send + 'static>> {
+        self.0.read_partition(partition)
+    }
+}
+
+impl Poolable for AdbcRocketConnection {
+    type Manager = AdbcConnectionManager<AdbcRocketDatabase>;
+    // `from_driver` defers all failures to the first `connect()` call, so
+    // building the manager itself can't fail here.
+    type Error = std::convert::Infallible;
+
+    fn pool(db_name: &str, rocket: &Rocket<Build>) -> PoolResult<Self> {
+        let config = Config::from(db_name, rocket)?;
+
+        // `Config::url` carries the driver path/name; any further
+        // database-level options live alongside it under `options`, since
+        // Rocket's built-in `Config` only models a bare connection string.
+        let options: HashMap<String, String> = rocket
+            .figment()
+            .extract_inner(&format!("databases.{db_name}.options"))
+            .unwrap_or_default();
+
+        let manager: AdbcConnectionManager<AdbcRocketDatabase> =
+            AdbcConnectionManager::from_driver(config.url, options);
+
+        let pool = r2d2::Pool::builder()
+            .max_size(config.pool_size)
+            .connection_timeout(Duration::from_secs(config.timeout as u64))
+            .build(manager)?;
+
+        Ok(pool)
+    }
+}